@@ -2,7 +2,7 @@
 //!
 //! This driver was built using [`embedded-hal`] traits.
 //!
-//! [`embedded-hal`]: https://docs.rs/embedded-hal/~0.2
+//! [`embedded-hal`]: https://docs.rs/embedded-hal/~1.0
 //!
 //! # Examples
 //!
@@ -14,33 +14,69 @@
 
 use embedded_hal as hal;
 
-use hal::digital::v2::OutputPin;
+use hal::delay::DelayNs;
+use hal::digital::{OutputPin, PinState};
+
+/// The wiring of the display's segment LEDs, i.e. which logic level on a segment pin turns that
+/// segment on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    /// The segments share a common cathode, so a segment is lit by driving its pin high
+    CommonCathode,
+    /// The segments share a common anode (or are sunk through a transistor/inverter), so a
+    /// segment is lit by driving its pin low
+    CommonAnode,
+}
+
+/// Segment bitmasks for the hexadecimal digits `0`-`F`, indexed by value, for use with
+/// [`SevenSeg::display_raw`]. Bit 0 selects segment `a`, bit 1 `b`, ... bit 6 `g`; bit 7 is
+/// unused. [`SevenSeg::display`] is just a lookup into this table, so it's public for anyone
+/// who wants to define their own glyphs or build animations out of raw bit patterns.
+pub const FONT: [u8; 16] = [
+    0x3F, // 0
+    0x30, // 1
+    0x5B, // 2
+    0x79, // 3
+    0x74, // 4
+    0x6D, // 5
+    0x6F, // 6
+    0x38, // 7
+    0x7F, // 8
+    0x7D, // 9
+    0x7E, // A
+    0x67, // b
+    0x0F, // C
+    0x73, // d
+    0x4F, // E
+    0x4E, // F
+];
+
+/// The index of each segment within [`SevenSeg`]'s internal `[P; 7]` array
+const SEG_A: usize = 0;
+const SEG_B: usize = 1;
+const SEG_C: usize = 2;
+const SEG_D: usize = 3;
+const SEG_E: usize = 4;
+const SEG_F: usize = 5;
+const SEG_G: usize = 6;
 
 /// A structure representing the 7 segments of a 7-segment display
-pub struct SevenSeg<A, B, C, D, E, F, G> {
-    seg_a: A,
-    seg_b: B,
-    seg_c: C,
-    seg_d: D,
-    seg_e: E,
-    seg_f: F,
-    seg_g: G,
+pub struct SevenSeg<P> {
+    segments: [P; 7],
+    polarity: Polarity,
 }
 
-impl<A, B, C, D, E, F, G> SevenSeg<A, B, C, D, E, F, G>
+impl<P, ERR> SevenSeg<P>
 where
-    A: OutputPin,
-    B: OutputPin,
-    C: OutputPin,
-    D: OutputPin,
-    E: OutputPin,
-    F: OutputPin,
-    G: OutputPin,
+    P: OutputPin<Error = ERR>,
 {
     /// Create a new 7-segment display structure by passing in 7 GPIOs implementing the
     /// `OutputPin` trait for the segments `a`, `b`, `c`, `d`, `e` and `f` respectively
-    pub fn new(seg_a: A, seg_b: B, seg_c: C, seg_d: D, seg_e: E, seg_f: F, seg_g: G) -> Self {
-        Self {
+    ///
+    /// This assumes a common-cathode display; use [`Self::new_with_polarity`] for common-anode
+    /// parts or inverted wiring
+    pub fn new(seg_a: P, seg_b: P, seg_c: P, seg_d: P, seg_e: P, seg_f: P, seg_g: P) -> Self {
+        Self::new_with_polarity(
             seg_a,
             seg_b,
             seg_c,
@@ -48,239 +84,518 @@ where
             seg_e,
             seg_f,
             seg_g,
-        }
+            Polarity::CommonCathode,
+        )
+    }
+
+    /// Create a new 7-segment display structure by passing in 7 GPIOs implementing the
+    /// `OutputPin` trait for the segments `a`, `b`, `c`, `d`, `e` and `f` respectively, and the
+    /// display's [`Polarity`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_polarity(
+        seg_a: P,
+        seg_b: P,
+        seg_c: P,
+        seg_d: P,
+        seg_e: P,
+        seg_f: P,
+        seg_g: P,
+        polarity: Polarity,
+    ) -> Self {
+        Self::from_array([seg_a, seg_b, seg_c, seg_d, seg_e, seg_f, seg_g], polarity)
+    }
+
+    /// Create a new 7-segment display structure from an array of 7 GPIOs for the segments `a`
+    /// through `g`, in order, and the display's [`Polarity`]
+    pub fn from_array(segments: [P; 7], polarity: Polarity) -> Self {
+        Self { segments, polarity }
     }
 
     /// Release the 7 GPIOs previously occupied by the 7-segment display
-    pub fn release(self) -> (A, B, C, D, E, F, G) {
-        (
-            self.seg_a, self.seg_b, self.seg_c, self.seg_d, self.seg_e, self.seg_f, self.seg_g,
-        )
+    pub fn release(self) -> [P; 7] {
+        self.segments
+    }
+
+    /// Translate a segment's desired on/off `state` into the GPIO level that achieves it given
+    /// this display's `polarity`
+    fn level(&self, state: bool) -> PinState {
+        let on = match self.polarity {
+            Polarity::CommonCathode => state,
+            Polarity::CommonAnode => !state,
+        };
+        PinState::from(on)
+    }
+
+    /// Enable or disable the segment at `idx` (`0` is `a`, ... `6` is `g`) according to `state`
+    fn write(&mut self, idx: usize, state: bool) -> Result<(), ERR> {
+        self.segments[idx].set_state(self.level(state))
     }
 
     /// Disable the 7-segment display by pulling all GPIOs low
-    pub fn clear(&mut self) -> Result<(), ()> {
-        self.seg_a(false)?;
-        self.seg_b(false)?;
-        self.seg_c(false)?;
-        self.seg_d(false)?;
-        self.seg_e(false)?;
-        self.seg_f(false)?;
-        self.seg_g(false)
+    pub fn clear(&mut self) -> Result<(), ERR> {
+        for idx in 0..self.segments.len() {
+            self.write(idx, false)?;
+        }
+        Ok(())
     }
 
     /// Enable or disable segment `a` according to the `state`
-    pub fn seg_a(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_a.set_high().map_err(|_| ())
-        } else {
-            self.seg_a.set_low().map_err(|_| ())
-        }
+    pub fn seg_a(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_A, state)
     }
 
     /// Enable or disable segment `b` according to the `state`
-    pub fn seg_b(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_b.set_high().map_err(|_| ())
-        } else {
-            self.seg_b.set_low().map_err(|_| ())
-        }
+    pub fn seg_b(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_B, state)
     }
 
     /// Enable or disable segment `c` according to the `state`
-    pub fn seg_c(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_c.set_high().map_err(|_| ())
-        } else {
-            self.seg_c.set_low().map_err(|_| ())
-        }
+    pub fn seg_c(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_C, state)
     }
 
     /// Enable or disable segment `d` according to the `state`
-    pub fn seg_d(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_d.set_high().map_err(|_| ())
-        } else {
-            self.seg_d.set_low().map_err(|_| ())
-        }
+    pub fn seg_d(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_D, state)
     }
 
     /// Enable or disable segment `e` according to the `state`
-    pub fn seg_e(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_e.set_high().map_err(|_| ())
-        } else {
-            self.seg_e.set_low().map_err(|_| ())
-        }
+    pub fn seg_e(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_E, state)
     }
 
     /// Enable or disable segment `f` according to the `state`
-    pub fn seg_f(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_f.set_high().map_err(|_| ())
-        } else {
-            self.seg_f.set_low().map_err(|_| ())
-        }
+    pub fn seg_f(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_F, state)
     }
 
     /// Enable or disable segment `g` according to the `state`
-    pub fn seg_g(&mut self, state: bool) -> Result<(), ()> {
-        if state {
-            self.seg_g.set_high().map_err(|_| ())
-        } else {
-            self.seg_g.set_low().map_err(|_| ())
+    pub fn seg_g(&mut self, state: bool) -> Result<(), ERR> {
+        self.write(SEG_G, state)
+    }
+
+    /// Write all seven segments at once from a single bitmask: bit 0 is segment `a`, bit 1 `b`,
+    /// ... bit 6 `g`. See [`FONT`] for ready-made digit patterns.
+    pub fn display_raw(&mut self, segments: u8) -> Result<(), ERR> {
+        for idx in 0..self.segments.len() {
+            self.write(idx, segments & (1 << idx) != 0)?;
         }
+        Ok(())
     }
 
     /// Display the digit specified in `num`. Supported are all values in the hexadecimal system,
     /// that is `0` through `9` and `A` through `F`. Any other value will turn off the display.
-    pub fn display(&mut self, num: u8) -> Result<(), ()> {
-        match num {
-            0 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(false)
-            }
-            1 => {
-                self.seg_a(false)?;
-                self.seg_b(false)?;
-                self.seg_c(false)?;
-                self.seg_d(false)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(false)
-            }
-            2 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(false)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(false)?;
-                self.seg_g(true)
-            }
-            3 => {
-                self.seg_a(true)?;
-                self.seg_b(false)?;
-                self.seg_c(false)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            4 => {
-                self.seg_a(false)?;
-                self.seg_b(false)?;
-                self.seg_c(true)?;
-                self.seg_d(false)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            5 => {
-                self.seg_a(true)?;
-                self.seg_b(false)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(false)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            6 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(false)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            7 => {
-                self.seg_a(false)?;
-                self.seg_b(false)?;
-                self.seg_c(false)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(false)
-            }
-            8 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            9 => {
-                self.seg_a(true)?;
-                self.seg_b(false)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            10 => {
-                self.seg_a(false)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            11 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(false)?;
-                self.seg_e(false)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            12 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(false)?;
-                self.seg_f(false)?;
-                self.seg_g(false)
-            }
-            13 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(false)?;
-                self.seg_d(false)?;
-                self.seg_e(true)?;
-                self.seg_f(true)?;
-                self.seg_g(true)
-            }
-            14 => {
-                self.seg_a(true)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(false)?;
-                self.seg_f(false)?;
-                self.seg_g(true)
-            }
-            15 => {
-                self.seg_a(false)?;
-                self.seg_b(true)?;
-                self.seg_c(true)?;
-                self.seg_d(true)?;
-                self.seg_e(false)?;
-                self.seg_f(false)?;
-                self.seg_g(true)
-            }
-            _ => self.clear(),
+    pub fn display(&mut self, num: u8) -> Result<(), ERR> {
+        match FONT.get(num as usize) {
+            Some(&segments) => self.display_raw(segments),
+            None => self.clear(),
+        }
+    }
+
+    /// Display the character `c`. Covers the decimal digits and the letters that render legibly
+    /// on seven segments (`0-9 A b C d E F H L P U n o r t`), folding case where both forms share
+    /// a glyph. `'O'` is rendered as `0`, since the two are visually identical. Any other
+    /// character blanks the display.
+    pub fn display_char(&mut self, c: char) -> Result<(), ERR> {
+        let segments = match c {
+            '0'..='9' => FONT[c as usize - '0' as usize],
+            'A' | 'a' => FONT[10],
+            'B' | 'b' => FONT[11],
+            'C' | 'c' => FONT[12],
+            'D' | 'd' => FONT[13],
+            'E' | 'e' => FONT[14],
+            'F' | 'f' => FONT[15],
+            'H' | 'h' => 0x76,
+            'L' | 'l' => 0x07,
+            'N' | 'n' => 0x62,
+            'O' => FONT[0],
+            'o' => 0x63,
+            'P' | 'p' => 0x5E,
+            'R' | 'r' => 0x42,
+            'T' | 't' => 0x47,
+            'U' | 'u' => 0x37,
+            _ => return self.clear(),
+        };
+        self.display_raw(segments)
+    }
+
+    /// Display the ASCII character in `byte`. See [`Self::display_char`] for the supported set;
+    /// any other byte blanks the display.
+    pub fn display_ascii(&mut self, byte: u8) -> Result<(), ERR> {
+        self.display_char(byte as char)
+    }
+}
+
+/// A 7-segment display extended with an eighth pin for the decimal point, which almost every
+/// physical 7-segment module breaks out separately from the 7 segment lines.
+pub struct SevenSegDp<P, DP> {
+    display: SevenSeg<P>,
+    dp: DP,
+}
+
+impl<P, DP, ERR> SevenSegDp<P, DP>
+where
+    P: OutputPin<Error = ERR>,
+    DP: OutputPin<Error = ERR>,
+{
+    /// Create a new decimal-point-equipped display from a `SevenSeg` and the GPIO wired to the
+    /// decimal point segment
+    pub fn new(display: SevenSeg<P>, dp: DP) -> Self {
+        Self { display, dp }
+    }
+
+    /// Release the `SevenSeg` and the decimal point GPIO previously occupied by this display
+    pub fn release(self) -> (SevenSeg<P>, DP) {
+        (self.display, self.dp)
+    }
+
+    /// Disable the 7-segment display and its decimal point by pulling all GPIOs low
+    pub fn clear(&mut self) -> Result<(), ERR> {
+        self.display.clear()?;
+        self.set_dp(false)
+    }
+
+    /// Enable or disable the decimal point according to the `state`
+    pub fn set_dp(&mut self, state: bool) -> Result<(), ERR> {
+        self.dp.set_state(self.display.level(state))
+    }
+
+    /// Write all seven segments from `segments`, see [`SevenSeg::display_raw`]
+    pub fn display_raw(&mut self, segments: u8) -> Result<(), ERR> {
+        self.display.display_raw(segments)
+    }
+
+    /// Display the digit specified in `num`, see [`SevenSeg::display`]
+    pub fn display(&mut self, num: u8) -> Result<(), ERR> {
+        self.display.display(num)
+    }
+
+    /// Display the character `c`, see [`SevenSeg::display_char`]
+    pub fn display_char(&mut self, c: char) -> Result<(), ERR> {
+        self.display.display_char(c)
+    }
+
+    /// Display the ASCII character in `byte`, see [`SevenSeg::display_char`]
+    pub fn display_ascii(&mut self, byte: u8) -> Result<(), ERR> {
+        self.display.display_ascii(byte)
+    }
+}
+
+/// A multiplexed multi-digit 7-segment display built from several physical digits that share one
+/// set of segment lines and are enabled one at a time via a per-digit "common" pin, e.g. a
+/// 4-digit module like the 3642BS.
+///
+/// Driving it is classic time-division multiplexing: the segment lines are set for one digit,
+/// that digit's common pin is enabled for `refresh_us`, then it's disabled again before moving on
+/// to the next digit. Cycled fast enough, persistence of vision makes all digits appear lit at
+/// once.
+pub struct MultiSevenSeg<P, SEG, DELAY, const N: usize>
+where
+    P: OutputPin,
+    SEG: OutputPin,
+    DELAY: DelayNs,
+{
+    display: SevenSeg<P>,
+    digits: [SEG; N],
+    delay: DELAY,
+    refresh_us: u32,
+    values: [u8; N],
+    current: usize,
+}
+
+impl<P, SEG, DELAY, ERR, const N: usize> MultiSevenSeg<P, SEG, DELAY, N>
+where
+    P: OutputPin<Error = ERR>,
+    SEG: OutputPin<Error = ERR>,
+    DELAY: DelayNs,
+{
+    /// Create a new multiplexed display from a `SevenSeg` driving the shared segment lines, the
+    /// `N` per-digit common/select pins and a delay handle, refreshing each digit for
+    /// `refresh_us` microseconds.
+    pub fn new(display: SevenSeg<P>, digits: [SEG; N], delay: DELAY, refresh_us: u32) -> Self {
+        Self {
+            display,
+            digits,
+            delay,
+            refresh_us,
+            values: [0; N],
+            current: 0,
+        }
+    }
+
+    /// Release the `SevenSeg`, the digit-select GPIOs and the delay handle previously occupied by
+    /// this display
+    pub fn release(self) -> (SevenSeg<P>, [SEG; N], DELAY) {
+        (self.display, self.digits, self.delay)
+    }
+
+    /// Store `value` as a right-aligned decimal number, blanking unused leading digits, and
+    /// multiplex it out once across all `N` digits
+    ///
+    /// If `value` has more decimal digits than `N`, it can't be shown without dropping digits, so
+    /// rather than silently displaying a truncated, wrong number the whole display is blanked
+    /// instead (same convention as [`SevenSeg::display`] blanking on an out-of-range digit).
+    pub fn display_number(&mut self, value: u16) -> Result<(), ERR> {
+        let mut digits = [0u8; N];
+        let mut remaining = value;
+        for digit in digits.iter_mut().rev() {
+            *digit = (remaining % 10) as u8;
+            remaining /= 10;
+        }
+        if remaining != 0 {
+            return self.display_slice(&[Self::BLANK; N]);
         }
+
+        let mut leading_blanks = 0;
+        while leading_blanks + 1 < N && digits[leading_blanks] == 0 {
+            leading_blanks += 1;
+        }
+
+        let mut values = [Self::BLANK; N];
+        values[leading_blanks..].copy_from_slice(&digits[leading_blanks..]);
+        self.display_slice(&values)
+    }
+
+    /// Store `values`, one hexadecimal digit (or [`Self::BLANK`]) per physical digit, and
+    /// multiplex it out once across all `N` digits
+    pub fn display_slice(&mut self, values: &[u8; N]) -> Result<(), ERR> {
+        self.values = *values;
+        for _ in 0..N {
+            self.refresh()?;
+            self.delay.delay_us(self.refresh_us);
+        }
+        Ok(())
+    }
+
+    /// Advance the multiplexing cycle by one digit: disable the previously active digit, set the
+    /// segment lines to the next digit's stored value and enable it. Intended to be called
+    /// periodically, e.g. from a timer firing every `refresh_us`, so the display stays lit
+    /// without blocking the caller on a delay.
+    ///
+    /// Known limitation: unlike the segment pins, the digit-select pins are always driven high to
+    /// enable a digit and low to disable it, regardless of [`Polarity`]. Common-anode multiplexed
+    /// modules, whose digit commons are typically sourced active-low through a transistor, are
+    /// wired backwards by this and aren't supported yet.
+    pub fn refresh(&mut self) -> Result<(), ERR> {
+        self.digits[self.current].set_low()?;
+        self.current = (self.current + 1) % N;
+        self.display.display(self.values[self.current])?;
+        self.digits[self.current].set_high()
+    }
+
+    /// Value used in [`Self::display_number`] and accepted by [`Self::display_slice`] to blank a
+    /// digit
+    pub const BLANK: u8 = 16;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    /// A `MockPin` just remembers whether it was last driven high, so tests can read back which
+    /// segments a `display_raw`/`display_char` call lit up
+    #[derive(Default)]
+    struct MockPin {
+        high: bool,
+    }
+
+    impl hal::digital::ErrorType for MockPin {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for MockPin {
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.high = false;
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.high = true;
+            Ok(())
+        }
+    }
+
+    fn new_display() -> SevenSeg<MockPin> {
+        SevenSeg::new(
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+        )
+    }
+
+    /// Fold the released pins back into the bitmask that produced them, so assertions can be
+    /// written against the same bit patterns `display_raw` accepts
+    fn as_byte(pins: &[MockPin; 7]) -> u8 {
+        pins.iter()
+            .enumerate()
+            .filter(|(_, pin)| pin.high)
+            .fold(0u8, |byte, (idx, _)| byte | (1 << idx))
+    }
+
+    #[test]
+    fn display_raw_round_trips_through_the_pins() {
+        let mut display = new_display();
+        display.display_raw(FONT[1]).unwrap();
+        assert_eq!(as_byte(&display.release()), FONT[1]);
+    }
+
+    #[test]
+    fn common_anode_inverts_every_segment() {
+        let mut display = SevenSeg::new_with_polarity(
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            Polarity::CommonAnode,
+        );
+        display.display_raw(FONT[8]).unwrap(); // all segments "on"
+        assert_eq!(as_byte(&display.release()), 0x00); // driven low, since common-anode is active-low
+    }
+
+    #[test]
+    fn display_char_l_is_not_the_digit_seven() {
+        let mut display = new_display();
+        display.display_char('L').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x07);
+        assert_ne!(0x07, FONT[7]);
+    }
+
+    #[test]
+    fn display_char_u_closes_into_a_cup() {
+        let mut display = new_display();
+        display.display_char('U').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x37);
+    }
+
+    #[test]
+    fn display_char_h_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('H').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x76);
+    }
+
+    #[test]
+    fn display_char_n_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('N').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x62);
+    }
+
+    #[test]
+    fn display_char_lowercase_o_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('o').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x63);
+    }
+
+    #[test]
+    fn display_char_p_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('P').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x5E);
+    }
+
+    #[test]
+    fn display_char_r_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('R').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x42);
+    }
+
+    #[test]
+    fn display_char_t_matches_its_bitmask() {
+        let mut display = new_display();
+        display.display_char('T').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x47);
+    }
+
+    #[test]
+    fn display_ascii_renders_like_display_char() {
+        let mut display = new_display();
+        display.display_ascii(b'H').unwrap();
+        assert_eq!(as_byte(&display.release()), 0x76);
+    }
+
+    #[test]
+    fn set_dp_toggles_independently_of_polarity() {
+        let mut cathode = SevenSegDp::new(new_display(), MockPin::default());
+        cathode.set_dp(true).unwrap();
+        assert!(cathode.release().1.high);
+
+        let anode_display = SevenSeg::new_with_polarity(
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            MockPin::default(),
+            Polarity::CommonAnode,
+        );
+        let mut anode = SevenSegDp::new(anode_display, MockPin::default());
+        anode.set_dp(true).unwrap();
+        assert!(!anode.release().1.high);
+    }
+
+    #[test]
+    fn clear_blanks_segments_and_dp() {
+        let mut dp = SevenSegDp::new(new_display(), MockPin::default());
+        dp.display_char('H').unwrap();
+        dp.set_dp(true).unwrap();
+        dp.clear().unwrap();
+        let (inner, dp_pin) = dp.release();
+        assert_eq!(as_byte(&inner.release()), 0);
+        assert!(!dp_pin.high);
+    }
+
+    /// A no-op `DelayNs`, since multiplex tests don't need real timing
+    struct NoDelay;
+
+    impl DelayNs for NoDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    fn new_multi() -> MultiSevenSeg<MockPin, MockPin, NoDelay, 4> {
+        MultiSevenSeg::new(new_display(), Default::default(), NoDelay, 1000)
+    }
+
+    #[test]
+    fn display_number_that_fits_stores_the_right_digits() {
+        let mut multi = new_multi();
+        multi.display_number(1234).unwrap();
+        assert_eq!(multi.values, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn display_number_overflow_blanks_instead_of_truncating() {
+        // 10_000 has 5 decimal digits and won't fit across N = 4 digits
+        let mut multi = new_multi();
+        assert!(multi.display_number(10_000).is_ok());
+        assert_eq!(
+            multi.values,
+            [MultiSevenSeg::<MockPin, MockPin, NoDelay, 4>::BLANK; 4]
+        );
+    }
+
+    #[test]
+    fn display_slice_lights_exactly_one_digit_with_matching_segments() {
+        let mut multi = new_multi();
+        multi.display_slice(&[5, 9, 2, 7]).unwrap();
+
+        // A full cycle of N refresh() calls ends back on digit 0: every other digit pin must be
+        // disabled and the segment lines must show that digit's own value, not a stray one.
+        assert!(multi.digits[0].high);
+        assert!(!multi.digits[1].high);
+        assert!(!multi.digits[2].high);
+        assert!(!multi.digits[3].high);
+        assert_eq!(as_byte(&multi.display.segments), FONT[5]);
     }
 }